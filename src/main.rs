@@ -3,6 +3,9 @@
 
 extern crate alloc;
 
+mod save;
+mod solver;
+
 use alloc::vec;
 use alloc::vec::Vec;
 use core::panic::PanicInfo;
@@ -12,30 +15,97 @@ use uefi::proto::console::text::Key;
 
 const FRAMERATE: usize = 30;
 const FRAME_INTERVAL: usize = 1_000_000 / FRAMERATE;
-const GRID_SIZE: usize = 12;
-const BOMB_COUNT: usize = 50;
+// Expert-sized board (classic 30x16/99 mines), fixed at compile time. This only
+// covers decoupling board size from screen resolution via the camera/viewport
+// below; there's no in-game Beginner/Intermediate/Expert picker here, since that
+// would mean turning these into runtime fields threaded through `Game`, the
+// solver, and the save format instead of consts — a separate change.
+const GRID_WIDTH: usize = 30;
+const GRID_HEIGHT: usize = 16;
+const BOMB_COUNT: usize = 99;
+// How many times `plant_bombs` re-rolls the mine layout looking for one
+// `solver::is_solvable` can clear without guessing, before giving up and
+// shipping whatever it last rolled. This is a best-effort bias, not a
+// guarantee: at Expert's ~20% mine density, single-point + subset propagation
+// alone clears the board from a fresh seed only a small fraction of the time,
+// so most games still ship a layout that needs at least one guess somewhere.
+// Raising this trades longer generation time for slightly better odds; it does
+// not make the guarantee real.
+const LAYOUT_RETRY_ATTEMPTS: usize = 20;
 const GAME_OVER_DELAY: usize = 2_000_000;
+// Tiles are drawn at a fixed pixel size now instead of being shrunk to fit the
+// screen, so the camera pans over the board instead of the board shrinking.
+const TILE_SIZE: usize = 32;
 
 const COLOR_HIDDEN: BltPixel = BltPixel::new(30, 30, 30);
 const COLOR_REVEALED: BltPixel = BltPixel::new(100, 110, 120);
 const COLOR_BOMB: BltPixel = BltPixel::new(20, 20, 20);
 const COLOR_FLAG: BltPixel = BltPixel::new(200, 50, 50);
 const COLOR_SELECTION: BltPixel = BltPixel::new(255, 255, 0);
+const COLOR_HINT: BltPixel = BltPixel::new(0, 255, 255);
 const COLOR_BACKGROUND: BltPixel = BltPixel::new(10, 10, 10);
 const COLOR_LOSE: BltPixel = BltPixel::new(150, 20, 20);
 const COLOR_WIN: BltPixel = BltPixel::new(20, 150, 20);
-const DOT_COLORS: [BltPixel; 9] = [
+const NUMBER_COLORS: [BltPixel; 9] = [
     COLOR_REVEALED,
-    BltPixel::new(0, 100, 255),   
-    BltPixel::new(0, 150, 0),     
-    BltPixel::new(255, 0, 0),     
-    BltPixel::new(0, 0, 150),     
-    BltPixel::new(150, 0, 0),     
-    BltPixel::new(0, 150, 150),   
-    BltPixel::new(150, 0, 150),   
-    BltPixel::new(100, 100, 100), 
+    BltPixel::new(0, 100, 255),
+    BltPixel::new(0, 150, 0),
+    BltPixel::new(255, 0, 0),
+    BltPixel::new(0, 0, 150),
+    BltPixel::new(150, 0, 0),
+    BltPixel::new(0, 150, 150),
+    BltPixel::new(150, 0, 150),
+    BltPixel::new(100, 100, 100),
+];
+const COLOR_HUD_TEXT: BltPixel = BltPixel::new(230, 230, 230);
+const HUD_HEIGHT: usize = 10;
+
+// Fog-of-war "flashlight" mode: a circle of light with this radius in pixels
+// follows the selection, fading from full brightness at its center to
+// `FOG_MIN_AMBIENT`/255 at its edge; everything past it stays at that level.
+const FOG_RADIUS: usize = TILE_SIZE * 3;
+const FOG_MIN_AMBIENT: u32 = 40;
+
+// 8x8 bitmap font, one byte per row, MSB = leftmost pixel. Only the glyphs the
+// HUD and tile digits actually need are included; add more rows/chars here if
+// more text ever needs to be drawn.
+const FONT_CHARS: [char; 25] = [
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', ' ', ':', 'B', 'E', 'I', 'L', 'M', 'N', 'O',
+    'S', 'T', 'U', 'W', 'Y', '!',
+];
+const FONT_GLYPHS: [[u8; 8]; 25] = [
+    [0b01111100, 0b11000110, 0b11001110, 0b11010110, 0b11100110, 0b11000110, 0b01111100, 0b00000000], // 0
+    [0b00110000, 0b01110000, 0b00110000, 0b00110000, 0b00110000, 0b00110000, 0b11111100, 0b00000000], // 1
+    [0b01111000, 0b11001100, 0b00001100, 0b00011000, 0b00110000, 0b01100000, 0b11111100, 0b00000000], // 2
+    [0b01111000, 0b11001100, 0b00001100, 0b00111000, 0b00001100, 0b11001100, 0b01111000, 0b00000000], // 3
+    [0b00011000, 0b00111000, 0b01111000, 0b11011000, 0b11111100, 0b00011000, 0b00011000, 0b00000000], // 4
+    [0b11111100, 0b11000000, 0b11111000, 0b00001100, 0b00001100, 0b11001100, 0b01111000, 0b00000000], // 5
+    [0b00111000, 0b01100000, 0b11000000, 0b11111000, 0b11001100, 0b11001100, 0b01111000, 0b00000000], // 6
+    [0b11111100, 0b00001100, 0b00011000, 0b00110000, 0b01100000, 0b01100000, 0b01100000, 0b00000000], // 7
+    [0b01111000, 0b11001100, 0b11001100, 0b01111000, 0b11001100, 0b11001100, 0b01111000, 0b00000000], // 8
+    [0b01111000, 0b11001100, 0b11001100, 0b01111100, 0b00001100, 0b00011000, 0b01110000, 0b00000000], // 9
+    [0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000], // ' '
+    [0b00000000, 0b00110000, 0b00110000, 0b00000000, 0b00110000, 0b00110000, 0b00000000, 0b00000000], // ':'
+    [0b11111000, 0b01100100, 0b01100100, 0b01111000, 0b01100100, 0b01100100, 0b11111000, 0b00000000], // B
+    [0b11111100, 0b01100000, 0b01100000, 0b01111000, 0b01100000, 0b01100000, 0b11111100, 0b00000000], // E
+    [0b01111000, 0b00110000, 0b00110000, 0b00110000, 0b00110000, 0b00110000, 0b01111000, 0b00000000], // I
+    [0b01100000, 0b01100000, 0b01100000, 0b01100000, 0b01100000, 0b01100000, 0b01111100, 0b00000000], // L
+    [0b11000110, 0b11101110, 0b11111110, 0b11010110, 0b11000110, 0b11000110, 0b11000110, 0b00000000], // M
+    [0b11000110, 0b11100110, 0b11110110, 0b11011110, 0b11001110, 0b11000110, 0b11000110, 0b00000000], // N
+    [0b01111000, 0b11001100, 0b11001100, 0b11001100, 0b11001100, 0b11001100, 0b01111000, 0b00000000], // O
+    [0b01111100, 0b11000000, 0b11000000, 0b01111000, 0b00001100, 0b00001100, 0b11111000, 0b00000000], // S
+    [0b11111100, 0b00110000, 0b00110000, 0b00110000, 0b00110000, 0b00110000, 0b00110000, 0b00000000], // T
+    [0b11001100, 0b11001100, 0b11001100, 0b11001100, 0b11001100, 0b11001100, 0b01111000, 0b00000000], // U
+    [0b11000110, 0b11000110, 0b11000110, 0b11010110, 0b11111110, 0b11101110, 0b11000110, 0b00000000], // W
+    [0b11000110, 0b11000110, 0b01101100, 0b00111000, 0b00010000, 0b00010000, 0b00010000, 0b00000000], // Y
+    [0b00110000, 0b00110000, 0b00110000, 0b00110000, 0b00110000, 0b00000000, 0b00110000, 0b00000000], // !
 ];
 
+fn glyph_bitmap(glyph: char) -> Option<&'static [u8; 8]> {
+    let index = FONT_CHARS.iter().position(|&c| c == glyph)?;
+    Some(&FONT_GLYPHS[index])
+}
+
 #[global_allocator]
 static GLOBAL_ALLOCATOR: uefi::allocator::Allocator = uefi::allocator::Allocator;
 
@@ -57,6 +127,25 @@ impl Vec2 {
     }
 }
 
+// Shared by `Game::get_neighbors` and the solver, which needs neighbor sets
+// without holding a `Game` (it runs against scratch grids during generation).
+fn neighbors_of(x: usize, y: usize) -> Vec<Vec2> {
+    let mut neighbors = Vec::new();
+    for dy in -1..=1 {
+        for dx in -1..=1 {
+            if dx == 0 && dy == 0 {
+                continue;
+            }
+            let nx = x as isize + dx;
+            let ny = y as isize + dy;
+            if nx >= 0 && nx < GRID_WIDTH as isize && ny >= 0 && ny < GRID_HEIGHT as isize {
+                neighbors.push(Vec2::new(nx as usize, ny as usize));
+            }
+        }
+    }
+    neighbors
+}
+
 struct Buffer {
     width: usize,
     height: usize,
@@ -89,6 +178,21 @@ impl Buffer {
         })
     }
 
+    // Blits just one rectangle of the buffer instead of the whole frame, for when
+    // only a few tiles changed. `px_stride` tells the GOP how wide a full buffer
+    // row is, since the sub-rectangle itself is narrower than that.
+    fn blit_region(&self, gop: &mut ScopedProtocol<GraphicsOutput>, pos: Vec2, dims: Vec2) -> Result {
+        gop.blt(BltOp::BufferToVideo {
+            buffer: &self.pixels,
+            src: BltRegion::SubRectangle {
+                coords: (pos.x, pos.y),
+                px_stride: self.width,
+            },
+            dest: (pos.x, pos.y),
+            dims: (dims.x, dims.y),
+        })
+    }
+
     fn fill(&mut self, color: BltPixel) {
         self.pixels.iter_mut().for_each(|p| *p = color);
     }
@@ -102,6 +206,29 @@ impl Buffer {
             }
         }
     }
+
+    // Blits a single 8x8 font glyph at `pos`, each set bit becoming a `scale`x`scale`
+    // block so the text scales alongside `tile_size`. Unknown glyphs are skipped.
+    fn draw_glyph(&mut self, pos: Vec2, glyph: char, scale: usize, color: BltPixel) {
+        let Some(bitmap) = glyph_bitmap(glyph) else {
+            return;
+        };
+        for (row, bits) in bitmap.iter().enumerate() {
+            for col in 0..8 {
+                if bits & (0x80 >> col) != 0 {
+                    let block_pos = Vec2::new(pos.x + col * scale, pos.y + row * scale);
+                    self.draw_rect(block_pos, Vec2::new(scale, scale), color);
+                }
+            }
+        }
+    }
+
+    fn draw_text(&mut self, pos: Vec2, text: &str, scale: usize, color: BltPixel) {
+        let advance = (8 + 1) * scale;
+        for (i, glyph) in text.chars().enumerate() {
+            self.draw_glyph(Vec2::new(pos.x + i * advance, pos.y), glyph, scale, color);
+        }
+    }
 }
 
 struct Rng {
@@ -170,32 +297,117 @@ struct Game {
     status: GameStatus,
     is_first_move: bool,
     rng: Rng,
+    start_seconds: u32,
+    camera: Vec2,
+    hint: Option<Vec2>,
+    // Grid-space tiles touched since the last frame was blitted. Drained into
+    // sub-rectangle blits each frame instead of re-sending the whole screen.
+    dirty_tiles: Vec<Vec2>,
+    full_redraw: bool,
+    fog_enabled: bool,
+    // Per-offset attenuation lookup for fog mode, built on first toggle-on and
+    // reused after since the falloff shape never changes.
+    fog_table: Vec<u8>,
 }
 
 impl Game {
     fn new(seed: u64) -> Self {
         Self {
-            grid: vec![Tile::new(); GRID_SIZE * GRID_SIZE],
-            selection: Vec2::new(GRID_SIZE / 2, GRID_SIZE / 2),
+            grid: vec![Tile::new(); GRID_WIDTH * GRID_HEIGHT],
+            selection: Vec2::new(GRID_WIDTH / 2, GRID_HEIGHT / 2),
             status: GameStatus::Playing,
             is_first_move: true,
             rng: Rng::new(seed),
+            start_seconds: day_seconds(),
+            camera: Vec2::new(0, 0),
+            hint: None,
+            dirty_tiles: Vec::new(),
+            // First frame (and the frame right after a win/lose color fill) always
+            // needs a full blit since there's no prior frame to diff against.
+            full_redraw: true,
+            fog_enabled: false,
+            fog_table: Vec::new(),
+        }
+    }
+
+    fn mark_dirty(&mut self, pos: Vec2) {
+        if !self.dirty_tiles.contains(&pos) {
+            self.dirty_tiles.push(pos);
+        }
+    }
+
+    // Recenters the camera on the selection, clamped so the viewport never shows
+    // past the board edges. Call after every move so panning stays in sync.
+    fn update_camera(&mut self, viewport: Vec2) {
+        let old_camera = self.camera;
+        let board_px = Vec2::new(GRID_WIDTH * TILE_SIZE, GRID_HEIGHT * TILE_SIZE);
+        let sel_center = Vec2::new(
+            self.selection.x * TILE_SIZE + TILE_SIZE / 2,
+            self.selection.y * TILE_SIZE + TILE_SIZE / 2,
+        );
+        self.camera = Vec2::new(
+            clamp_camera_axis(sel_center.x, viewport.x, board_px.x),
+            clamp_camera_axis(sel_center.y, viewport.y, board_px.y),
+        );
+        if self.camera != old_camera {
+            // Panning shifts where every visible tile lands in the buffer, not just
+            // the selection's own cells, so the dirty-tile set can't capture it.
+            self.full_redraw = true;
+        }
+    }
+
+    fn flag_count(&self) -> usize {
+        self.grid
+            .iter()
+            .filter(|t| t.state == TileState::Flagged)
+            .count()
+    }
+
+    fn elapsed_seconds(&self) -> u32 {
+        let now = day_seconds();
+        if now >= self.start_seconds {
+            now - self.start_seconds
+        } else {
+            // wrapped past midnight
+            (86_400 - self.start_seconds) + now
         }
     }
 
     fn tile_mut(&mut self, x: usize, y: usize) -> &mut Tile {
-        &mut self.grid[y * GRID_SIZE + x]
+        &mut self.grid[y * GRID_WIDTH + x]
     }
 
     fn tile(&self, x: usize, y: usize) -> &Tile {
-        &self.grid[y * GRID_SIZE + x]
+        &self.grid[y * GRID_WIDTH + x]
     }
 
 
+    // Re-rolls the mine layout up to `LAYOUT_RETRY_ATTEMPTS` times, keeping the
+    // first one `solver::is_solvable` can clear from `safe_pos` without guessing.
+    // This is a best-effort bias toward a more-solvable opening, NOT a guarantee:
+    // single-point + subset propagation is too weak at Expert's mine density to
+    // reliably clear a whole board unassisted, so most games exhaust every retry
+    // and ship the last (unsolved-by-the-solver) layout rolled. The solver is
+    // guaranteed-sound where it's actually used for certainty: in-game hints
+    // (see `show_hint`) only ever highlight tiles it has proven safe, taking the
+    // player's flags as correct.
     fn plant_bombs(&mut self, safe_pos: Vec2) {
+        for _ in 0..LAYOUT_RETRY_ATTEMPTS {
+            for tile in self.grid.iter_mut() {
+                tile.is_bomb = false;
+                tile.neighboring_bombs = 0;
+            }
+            self.place_bombs_once(safe_pos);
+            if solver::is_solvable(self.grid.clone(), safe_pos) {
+                return;
+            }
+        }
+    }
+
+    fn place_bombs_once(&mut self, safe_pos: Vec2) {
         let mut safe_positions = Vec::new();
         safe_positions.push(safe_pos);
-        
+
         for neighbor in self.get_neighbors(safe_pos.x, safe_pos.y) {
             safe_positions.push(neighbor);
         }
@@ -203,22 +415,22 @@ impl Game {
         let mut bombs_placed = 0;
         let mut attempts = 0;
         let max_attempts = BOMB_COUNT * 10;
-        
+
         while bombs_placed < BOMB_COUNT && attempts < max_attempts {
-            let x = self.rng.next_in_range(0..GRID_SIZE);
-            let y = self.rng.next_in_range(0..GRID_SIZE);
-            
+            let x = self.rng.next_in_range(0..GRID_WIDTH);
+            let y = self.rng.next_in_range(0..GRID_HEIGHT);
+
             let is_safe = safe_positions.iter().any(|pos| pos.x == x && pos.y == y);
-            
+
             if !is_safe && !self.tile(x, y).is_bomb {
                 self.tile_mut(x, y).is_bomb = true;
                 bombs_placed += 1;
             }
             attempts += 1;
         }
-        
-        for y in 0..GRID_SIZE {
-            for x in 0..GRID_SIZE {
+
+        for y in 0..GRID_HEIGHT {
+            for x in 0..GRID_WIDTH {
                 if !self.tile(x, y).is_bomb {
                     let count = self.count_neighbor_bombs(x, y);
                     self.tile_mut(x, y).neighboring_bombs = count;
@@ -228,20 +440,7 @@ impl Game {
     }
 
     fn get_neighbors(&self, x: usize, y: usize) -> Vec<Vec2> {
-        let mut neighbors = Vec::new();
-        for dy in -1..=1 {
-            for dx in -1..=1 {
-                if dx == 0 && dy == 0 {
-                    continue;
-                }
-                let nx = x as isize + dx;
-                let ny = y as isize + dy;
-                if nx >= 0 && nx < GRID_SIZE as isize && ny >= 0 && ny < GRID_SIZE as isize {
-                    neighbors.push(Vec2::new(nx as usize, ny as usize));
-                }
-            }
-        }
-        neighbors
+        neighbors_of(x, y)
     }
 
     fn count_neighbor_bombs(&self, x: usize, y: usize) -> u8 {
@@ -259,16 +458,46 @@ impl Game {
             return;
         }
         match key_char {
-            'w' => self.selection.y = self.selection.y.saturating_sub(1),
-            's' => self.selection.y = (self.selection.y + 1).min(GRID_SIZE - 1),
-            'a' => self.selection.x = self.selection.x.saturating_sub(1),
-            'd' => self.selection.x = (self.selection.x + 1).min(GRID_SIZE - 1),
+            'w' => self.move_selection(0, -1),
+            's' => self.move_selection(0, 1),
+            'a' => self.move_selection(-1, 0),
+            'd' => self.move_selection(1, 0),
             'f' => self.toggle_flag(),
             't' => self.reveal_selected(),
+            'h' => self.show_hint(),
+            'p' => save::save(self),
+            'l' => self.toggle_fog(),
             _ => {}
         }
     }
 
+    fn move_selection(&mut self, dx: isize, dy: isize) {
+        let old = self.selection;
+        let new_x = (self.selection.x as isize + dx).clamp(0, GRID_WIDTH as isize - 1) as usize;
+        let new_y = (self.selection.y as isize + dy).clamp(0, GRID_HEIGHT as isize - 1) as usize;
+        self.selection = Vec2::new(new_x, new_y);
+        if self.selection != old {
+            if self.fog_enabled {
+                // The light moves with the selection, so more than just the two
+                // tiles themselves get brighter/dimmer; easiest to redraw it all.
+                self.full_redraw = true;
+            } else {
+                self.mark_dirty(old);
+                self.mark_dirty(self.selection);
+            }
+        }
+    }
+
+    // Toggles the flashlight effect on/off. Building the falloff table is a bit
+    // of work, so it's done once on first use and kept around after that.
+    fn toggle_fog(&mut self) {
+        self.fog_enabled = !self.fog_enabled;
+        if self.fog_enabled && self.fog_table.is_empty() {
+            self.fog_table = fog_falloff_table();
+        }
+        self.full_redraw = true;
+    }
+
     fn toggle_flag(&mut self) {
         let tile = self.tile_mut(self.selection.x, self.selection.y);
         match tile.state {
@@ -276,6 +505,27 @@ impl Game {
             TileState::Flagged => tile.state = TileState::Hidden,
             TileState::Revealed => {}
         }
+        self.mark_dirty(self.selection);
+        self.clear_hint();
+    }
+
+    // Runs the solver against the current board and highlights the first
+    // provably-safe tile it can deduce, if any.
+    fn show_hint(&mut self) {
+        let new_hint = solver::deduce(&self.grid).safe.first().copied();
+        if let Some(old) = self.hint {
+            self.mark_dirty(old);
+        }
+        if let Some(new) = new_hint {
+            self.mark_dirty(new);
+        }
+        self.hint = new_hint;
+    }
+
+    fn clear_hint(&mut self) {
+        if let Some(old) = self.hint.take() {
+            self.mark_dirty(old);
+        }
     }
 
     fn reveal_selected(&mut self) {
@@ -283,26 +533,40 @@ impl Game {
             self.plant_bombs(self.selection);
             self.is_first_move = false;
         }
+        self.clear_hint();
         let sel_x = self.selection.x;
         let sel_y = self.selection.y;
         self.reveal_recursive(sel_x, sel_y);
         if self.tile(sel_x, sel_y).is_bomb && self.tile(sel_x, sel_y).state == TileState::Revealed {
             self.status = GameStatus::Lose;
             self.reveal_all_bombs();
+            // The loss/win screens fill the whole display with a solid color next,
+            // so there's no point computing a dirty set for this last frame.
+            self.full_redraw = true;
         } else {
             self.check_win_condition();
+            if matches!(self.status, GameStatus::Win) {
+                self.full_redraw = true;
+            }
         }
     }
 
+    // Explicit worklist instead of recursion: a large empty board could otherwise
+    // nest hundreds of calls deep, which is risky on UEFI boot services' limited
+    // stack (and the `loop {}` panic handler would turn an overflow into a silent hang).
     fn reveal_recursive(&mut self, x: usize, y: usize) {
-        let tile = self.tile_mut(x, y);
-        if tile.state != TileState::Hidden {
-            return;
-        }
-        tile.state = TileState::Revealed;
-        if tile.neighboring_bombs == 0 && !tile.is_bomb {
-            for neighbor in self.get_neighbors(x, y) {
-                self.reveal_recursive(neighbor.x, neighbor.y);
+        let mut stack = vec![Vec2::new(x, y)];
+        while let Some(pos) = stack.pop() {
+            let tile = self.tile_mut(pos.x, pos.y);
+            if tile.state != TileState::Hidden {
+                continue;
+            }
+            tile.state = TileState::Revealed;
+            self.mark_dirty(pos);
+            if tile.neighboring_bombs == 0 && !tile.is_bomb {
+                for neighbor in self.get_neighbors(pos.x, pos.y) {
+                    stack.push(neighbor);
+                }
             }
         }
     }
@@ -327,22 +591,166 @@ impl Game {
     }
 }
 
+// Clamps one axis of the camera so the selection stays centered in the viewport
+// without ever scrolling past the board edges; boards that fit entirely inside
+// the viewport are just centered instead of panned.
+fn clamp_camera_axis(center_px: usize, viewport: usize, board_px: usize) -> usize {
+    if board_px <= viewport {
+        0
+    } else {
+        let half_viewport = viewport / 2;
+        center_px
+            .saturating_sub(half_viewport)
+            .min(board_px - viewport)
+    }
+}
+
+// Top-left of the board within the buffer when it's smaller than the viewport on
+// that axis (camera is clamped to 0 in that case, so this is where it gets centered).
+fn board_center_offset(buffer_width: usize, buffer_height: usize) -> Vec2 {
+    let viewport_height = buffer_height - HUD_HEIGHT;
+    let board_px_width = GRID_WIDTH * TILE_SIZE;
+    let board_px_height = GRID_HEIGHT * TILE_SIZE;
+    Vec2::new(
+        (buffer_width.saturating_sub(board_px_width)) / 2,
+        (viewport_height.saturating_sub(board_px_height)) / 2,
+    )
+}
+
+// Screen-space rectangle for one grid tile, clipped to the visible buffer area.
+// Returns None if the tile is fully offscreen. Used to turn a dirty tile set into
+// the bounding rectangles that actually get re-blitted to the GOP.
+fn tile_screen_rect(grid_pos: Vec2, game: &Game, buffer_width: usize, buffer_height: usize) -> Option<(Vec2, Vec2)> {
+    let center = board_center_offset(buffer_width, buffer_height);
+    let raw_x = center.x as isize + (grid_pos.x * TILE_SIZE) as isize - game.camera.x as isize;
+    let raw_y =
+        HUD_HEIGHT as isize + center.y as isize + (grid_pos.y * TILE_SIZE) as isize - game.camera.y as isize;
+
+    let left = raw_x.max(0);
+    let top = raw_y.max(HUD_HEIGHT as isize);
+    let right = (raw_x + TILE_SIZE as isize).min(buffer_width as isize);
+    let bottom = (raw_y + TILE_SIZE as isize).min(buffer_height as isize);
+
+    if right <= left || bottom <= top {
+        None
+    } else {
+        Some((
+            Vec2::new(left as usize, top as usize),
+            Vec2::new((right - left) as usize, (bottom - top) as usize),
+        ))
+    }
+}
+
+// Integer square root (Newton's method). There's no libm in this `no_std` build,
+// so this stands in for `f32::sqrt` when ranking pixels by distance.
+fn isqrt(n: u32) -> u32 {
+    if n == 0 {
+        return 0;
+    }
+    let mut x = n;
+    let mut y = (x + 1) / 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+// Per-offset attenuation (0-255) for every pixel within `FOG_RADIUS` of the light
+// center, indexed by `(dy + FOG_RADIUS) * (2 * FOG_RADIUS + 1) + (dx + FOG_RADIUS)`.
+// Bounding the table to the radius instead of the whole screen keeps it small.
+fn fog_falloff_table() -> Vec<u8> {
+    let side = 2 * FOG_RADIUS + 1;
+    let mut table = vec![0u8; side * side];
+    for oy in 0..side {
+        for ox in 0..side {
+            let dx = ox as isize - FOG_RADIUS as isize;
+            let dy = oy as isize - FOG_RADIUS as isize;
+            let dist = isqrt((dx * dx + dy * dy) as u32);
+            let attenuation = if dist >= FOG_RADIUS as u32 {
+                FOG_MIN_AMBIENT
+            } else {
+                let lit = 255 - dist * (255 - FOG_MIN_AMBIENT) / FOG_RADIUS as u32;
+                lit.max(FOG_MIN_AMBIENT)
+            };
+            table[oy * side + ox] = attenuation as u8;
+        }
+    }
+    table
+}
+
+// Dims the already-composed frame by distance from `light_center`, down to the
+// ambient floor outside the circle of light, for fog-of-war mode. Runs as a final post-process pass over `draw_game`'s buffer.
+fn apply_fog(game: &Game, buffer: &mut Buffer, light_center: Vec2) {
+    let side = 2 * FOG_RADIUS + 1;
+    for y in HUD_HEIGHT..buffer.height {
+        for x in 0..buffer.width {
+            let dx = x as isize - light_center.x as isize;
+            let dy = y as isize - light_center.y as isize;
+            let attenuation = if dx.unsigned_abs() > FOG_RADIUS || dy.unsigned_abs() > FOG_RADIUS {
+                FOG_MIN_AMBIENT as u16
+            } else {
+                let ox = (dx + FOG_RADIUS as isize) as usize;
+                let oy = (dy + FOG_RADIUS as isize) as usize;
+                game.fog_table[oy * side + ox] as u16
+            };
+            if let Some(pixel) = buffer.pixel(x, y) {
+                pixel.red = (pixel.red as u16 * attenuation / 255) as u8;
+                pixel.green = (pixel.green as u16 * attenuation / 255) as u8;
+                pixel.blue = (pixel.blue as u16 * attenuation / 255) as u8;
+            }
+        }
+    }
+}
+
 fn draw_game(game: &Game, buffer: &mut Buffer) {
     buffer.fill(COLOR_BACKGROUND);
     let (buffer_width, buffer_height) = (buffer.width, buffer.height);
-    let smaller_dim = buffer_width.min(buffer_height);
-    let tile_size = smaller_dim / GRID_SIZE;
-    let grid_pixel_size = tile_size * GRID_SIZE;
-    let offset_x = (buffer_width - grid_pixel_size) / 2;
-    let offset_y = (buffer_height - grid_pixel_size) / 2;
+    let viewport_top = HUD_HEIGHT;
+    let viewport_height = buffer_height - HUD_HEIGHT;
+    let tile_size = TILE_SIZE;
     let tile_padding = tile_size / 10;
 
-    for y in 0..GRID_SIZE {
-        for x in 0..GRID_SIZE {
+    draw_hud(game, buffer);
+
+    let center = board_center_offset(buffer_width, buffer_height);
+    let (center_x, center_y) = (center.x, center.y);
+
+    for y in 0..GRID_HEIGHT {
+        for x in 0..GRID_WIDTH {
+            let screen_x = center_x as isize + (x * tile_size) as isize - game.camera.x as isize;
+            let screen_y = viewport_top as isize + center_y as isize + (y * tile_size) as isize
+                - game.camera.y as isize;
+
+            let off_screen = screen_x + tile_size as isize <= 0
+                || screen_x >= buffer_width as isize
+                || screen_y + tile_size as isize <= viewport_top as isize
+                || screen_y >= buffer_height as isize;
+            if off_screen {
+                continue;
+            }
+
+            // Crop the padded inner rect to the visible area (same approach as
+            // `tile_screen_rect`) instead of clamping just its top-left corner and
+            // keeping full `tile_size` dims: a tile straddling the viewport edge
+            // during a pan would otherwise get a full-size rect drawn from the
+            // clamped corner, showing the wrong half of itself and stomping the
+            // neighboring tile drawn right after it.
+            let inner_left = (screen_x + tile_padding as isize).max(0);
+            let inner_top = (screen_y + tile_padding as isize).max(viewport_top as isize);
+            let inner_right = (screen_x + tile_size as isize - tile_padding as isize).min(buffer_width as isize);
+            let inner_bottom =
+                (screen_y + tile_size as isize - tile_padding as isize).min(buffer_height as isize);
+            if inner_right <= inner_left || inner_bottom <= inner_top {
+                continue;
+            }
+            let inner_pos = Vec2::new(inner_left as usize, inner_top as usize);
+            let inner_dims = Vec2::new(
+                (inner_right - inner_left) as usize,
+                (inner_bottom - inner_top) as usize,
+            );
+
             let tile = game.tile(x, y);
-            let pos = Vec2::new(offset_x + x * tile_size, offset_y + y * tile_size);
-            let inner_pos = Vec2::new(pos.x + tile_padding, pos.y + tile_padding);
-            let inner_dims = Vec2::new(tile_size - 2 * tile_padding, tile_size - 2 * tile_padding);
             let color = match tile.state {
                 TileState::Hidden => COLOR_HIDDEN,
                 TileState::Flagged => COLOR_HIDDEN,
@@ -366,44 +774,26 @@ fn draw_game(game: &Game, buffer: &mut Buffer) {
             }
 
             if tile.state == TileState::Revealed && !tile.is_bomb && tile.neighboring_bombs > 0 {
-                let dot_color = DOT_COLORS[tile.neighboring_bombs as usize];
-                let dot_size = (inner_dims.x / 5).max(1);
-                let center_x = inner_pos.x + inner_dims.x / 2;
-                let center_y = inner_pos.y + inner_dims.y / 2;
-
-                let positions = match tile.neighboring_bombs {
-                    1 => vec![Vec2::new(center_x - dot_size / 2, center_y - dot_size / 2)],
-                    2 => vec![
-                        Vec2::new(center_x - dot_size * 2, center_y - dot_size / 2),
-                        Vec2::new(center_x + dot_size, center_y - dot_size / 2),
-                    ],
-                    3 => vec![
-                        Vec2::new(center_x - dot_size * 2, center_y - dot_size * 2),
-                        Vec2::new(center_x - dot_size / 2, center_y - dot_size / 2),
-                        Vec2::new(center_x + dot_size, center_y + dot_size),
-                    ],
-                    // i got kinda lazy and bored having to figure out exactly where the rectangles go...
-                    // lets hope the user doesn't get more than 4 bombs. if they do, im pretty sure
-                    // just a big square is good enough to let them know there's a whole lotta bombs.
-                    _ => {
-                        let big_dot_size = dot_size * 2;
-                        vec![Vec2::new(center_x - big_dot_size / 2, center_y - big_dot_size / 2)]
-                    }
-                };
-                let dot_dims = match tile.neighboring_bombs {
-                    _ if tile.neighboring_bombs >= 4 => Vec2::new(dot_size*2, dot_size*2),
-                    _ => Vec2::new(dot_size, dot_size)
-                };
-                for pos in positions {
-                    buffer.draw_rect(pos, dot_dims, dot_color);
-                }
+                let number_color = NUMBER_COLORS[tile.neighboring_bombs as usize];
+                let scale = (inner_dims.x / 8).max(1);
+                let glyph_pos = Vec2::new(
+                    inner_pos.x + (inner_dims.x.saturating_sub(8 * scale)) / 2,
+                    inner_pos.y + (inner_dims.y.saturating_sub(8 * scale)) / 2,
+                );
+                let digit = (b'0' + tile.neighboring_bombs) as char;
+                buffer.draw_glyph(glyph_pos, digit, scale, number_color);
             }
         }
     }
 
+    // The camera is always clamped to keep the selection inside the viewport, so
+    // this can assume a non-negative, on-screen position unlike the tile loop above.
     let sel_pos = Vec2::new(
-        offset_x + game.selection.x * tile_size,
-        offset_y + game.selection.y * tile_size,
+        (center_x as isize + (game.selection.x * tile_size) as isize - game.camera.x as isize)
+            .max(0) as usize,
+        (viewport_top as isize + center_y as isize + (game.selection.y * tile_size) as isize
+            - game.camera.y as isize)
+            .max(viewport_top as isize) as usize,
     );
     let thickness = (tile_padding / 2).max(1);
 
@@ -419,6 +809,97 @@ fn draw_game(game: &Game, buffer: &mut Buffer) {
         Vec2::new(thickness, tile_size),
         COLOR_SELECTION,
     );
+
+    if let Some(hint) = game.hint {
+        let hint_screen_x = center_x as isize + (hint.x * tile_size) as isize - game.camera.x as isize;
+        let hint_screen_y = viewport_top as isize + center_y as isize + (hint.y * tile_size) as isize
+            - game.camera.y as isize;
+        let on_screen = hint_screen_x >= 0
+            && hint_screen_x + tile_size as isize <= buffer_width as isize
+            && hint_screen_y >= viewport_top as isize
+            && hint_screen_y + tile_size as isize <= buffer_height as isize;
+        if on_screen {
+            let hint_pos = Vec2::new(hint_screen_x as usize, hint_screen_y as usize);
+            buffer.draw_rect(hint_pos, Vec2::new(tile_size, thickness), COLOR_HINT);
+            buffer.draw_rect(
+                Vec2::new(hint_pos.x, hint_pos.y + tile_size - thickness),
+                Vec2::new(tile_size, thickness),
+                COLOR_HINT,
+            );
+            buffer.draw_rect(hint_pos, Vec2::new(thickness, tile_size), COLOR_HINT);
+            buffer.draw_rect(
+                Vec2::new(hint_pos.x + tile_size - thickness, hint_pos.y),
+                Vec2::new(thickness, tile_size),
+                COLOR_HINT,
+            );
+        }
+    }
+
+    let status_text = match game.status {
+        GameStatus::Win => Some("YOU WIN!"),
+        GameStatus::Lose => Some("YOU LOSE!"),
+        GameStatus::Playing => None,
+    };
+    if let Some(text) = status_text {
+        let scale = (tile_size / 8).max(1);
+        let text_width = text.chars().count() * (8 + 1) * scale;
+        let text_pos = Vec2::new(
+            (buffer_width.saturating_sub(text_width)) / 2,
+            viewport_top + (viewport_height.saturating_sub(8 * scale)) / 2,
+        );
+        buffer.draw_text(text_pos, text, scale, COLOR_HUD_TEXT);
+    }
+
+    if game.fog_enabled {
+        let light_center = Vec2::new(sel_pos.x + tile_size / 2, sel_pos.y + tile_size / 2);
+        apply_fog(game, buffer, light_center);
+    }
+}
+
+fn draw_hud(game: &Game, buffer: &mut Buffer) {
+    let remaining_bombs = BOMB_COUNT.saturating_sub(game.flag_count());
+    let elapsed = game.elapsed_seconds();
+
+    let mut bombs_text: alloc::string::String = alloc::string::String::new();
+    bombs_text.push_str("BOMBS:");
+    push_padded_number(&mut bombs_text, remaining_bombs as u32, 3);
+
+    let mut time_text: alloc::string::String = alloc::string::String::new();
+    time_text.push_str("TIME:");
+    push_padded_number(&mut time_text, elapsed, 4);
+
+    let scale = 1;
+    buffer.draw_text(Vec2::new(2, 1), &bombs_text, scale, COLOR_HUD_TEXT);
+
+    let time_width = time_text.chars().count() * (8 + 1) * scale;
+    buffer.draw_text(
+        Vec2::new(buffer.width.saturating_sub(time_width + 2), 1),
+        &time_text,
+        scale,
+        COLOR_HUD_TEXT,
+    );
+}
+
+// Appends `value` to `text` zero-padded to `digits` wide, which keeps the HUD
+// columns from jittering as numbers change.
+fn push_padded_number(text: &mut alloc::string::String, value: u32, digits: usize) {
+    let mut buf = [0u8; 10];
+    let mut len = 0;
+    let mut remaining = value;
+    loop {
+        buf[len] = b'0' + (remaining % 10) as u8;
+        len += 1;
+        remaining /= 10;
+        if remaining == 0 || len == buf.len() {
+            break;
+        }
+    }
+    for _ in len..digits {
+        text.push('0');
+    }
+    for i in (0..len).rev() {
+        text.push(buf[i] as char);
+    }
 }
 
 fn get_key_press() -> Option<char> {
@@ -433,6 +914,9 @@ fn get_key_press() -> Option<char> {
             let d_lo = Char16::try_from('d').unwrap();
             let f_lo = Char16::try_from('f').unwrap();
             let t_lo = Char16::try_from('t').unwrap();
+            let h_lo = Char16::try_from('h').unwrap();
+            let p_lo = Char16::try_from('p').unwrap();
+            let l_lo = Char16::try_from('l').unwrap();
 
             if key == w_lo {
                 Some('w')
@@ -446,6 +930,12 @@ fn get_key_press() -> Option<char> {
                 Some('f')
             } else if key == t_lo {
                 Some('t')
+            } else if key == h_lo {
+                Some('h')
+            } else if key == p_lo {
+                Some('p')
+            } else if key == l_lo {
+                Some('l')
             } else {
                 None
             }
@@ -454,11 +944,40 @@ fn get_key_press() -> Option<char> {
     }
 }
 
+// Blits just the regions that changed since the last frame, falling back to a
+// full-screen blit whenever `full_redraw` is set: the first frame of a game, a
+// camera pan, a fog toggle or fog-mode move, and the final win/lose frame.
+fn present(game: &mut Game, buffer: &Buffer, gop: &mut ScopedProtocol<GraphicsOutput>) {
+    if game.full_redraw {
+        buffer.blit(gop).unwrap();
+        game.full_redraw = false;
+    } else {
+        // The HUD (bomb count, elapsed time) can change every frame regardless of
+        // tile activity, so its strip always rides along with whatever else changed.
+        buffer
+            .blit_region(gop, Vec2::new(0, 0), Vec2::new(buffer.width, HUD_HEIGHT))
+            .unwrap();
+        for &pos in &game.dirty_tiles {
+            if let Some((rect_pos, rect_dims)) = tile_screen_rect(pos, game, buffer.width, buffer.height) {
+                buffer.blit_region(gop, rect_pos, rect_dims).unwrap();
+            }
+        }
+    }
+    game.dirty_tiles.clear();
+}
+
 fn get_random_seed() -> u64 {
     let initial_time = uefi::runtime::get_time().unwrap();
     return initial_time.nanosecond() as u64 + initial_time.second() as u64 * 1_000_000_000;
 }
 
+// Seconds since midnight, used as a cheap stopwatch for the HUD. Wall-clock time
+// via `uefi::runtime::get_time` is the only clock available here.
+fn day_seconds() -> u32 {
+    let time = uefi::runtime::get_time().unwrap();
+    time.hour() as u32 * 3600 + time.minute() as u32 * 60 + time.second() as u32
+}
+
 
 #[entry]
 fn main() -> Status {
@@ -470,11 +989,16 @@ fn main() -> Status {
     let (width, height) = gop.current_mode_info().resolution();
     let mut buffer = Buffer::new(width, height);
 
-    let mut game = Game::new(get_random_seed());
+    // Resume a saved match if one was left behind by a previous boot ('p' to save);
+    // any save that's missing, foreign, or from an incompatible version is ignored.
+    let mut game = save::load().unwrap_or_else(|| Game::new(get_random_seed()));
+    let viewport = Vec2::new(width, height.saturating_sub(HUD_HEIGHT));
+    game.update_camera(viewport);
 
     loop {
         if let Some(key_char) = get_key_press() {
             game.handle_input(key_char);
+            game.update_camera(viewport);
         }
 
         match game.status {
@@ -483,7 +1007,7 @@ fn main() -> Status {
             }
             GameStatus::Lose => {
                 draw_game(&game, &mut buffer);
-                buffer.blit(&mut gop).unwrap();
+                present(&mut game, &buffer, &mut gop);
                 boot::stall(GAME_OVER_DELAY);
                 // nay!!
                 buffer.fill(COLOR_LOSE);
@@ -491,22 +1015,24 @@ fn main() -> Status {
                 boot::stall(GAME_OVER_DELAY);
 
                 game = Game::new(get_random_seed());
+                game.update_camera(viewport);
                 continue;
             }
             GameStatus::Win => {
                 draw_game(&game, &mut buffer);
-                buffer.blit(&mut gop).unwrap();
+                present(&mut game, &buffer, &mut gop);
                 boot::stall(GAME_OVER_DELAY);
                 // yay!!
                 buffer.fill(COLOR_WIN);
                 buffer.blit(&mut gop).unwrap();
                 boot::stall(GAME_OVER_DELAY);
                 game = Game::new(get_random_seed());
+                game.update_camera(viewport);
                 continue;
             }
         }
 
-        buffer.blit(&mut gop).unwrap();
+        present(&mut game, &buffer, &mut gop);
         boot::stall(FRAME_INTERVAL);
     }
 }
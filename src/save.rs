@@ -0,0 +1,120 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use uefi::runtime::{VariableAttributes, VariableVendor};
+use uefi::{cstr16, guid, CStr16};
+
+use crate::{Game, GameStatus, Rng, Tile, TileState, Vec2, GRID_HEIGHT, GRID_WIDTH};
+
+const MAGIC: [u8; 4] = *b"MSWU";
+const VERSION: u8 = 1;
+const TILE_COUNT: usize = GRID_WIDTH * GRID_HEIGHT;
+// magic + version + rng seed + is_first_move + start_seconds + selection(x,y) + status + tiles
+const SAVE_LEN: usize = 4 + 1 + 8 + 1 + 4 + 2 + 2 + 1 + TILE_COUNT;
+
+const SAVE_NAME: &CStr16 = cstr16!("MinesweeperSave");
+// Scoped to this app so the variable can't collide with one firmware or another
+// UEFI application happens to define.
+const SAVE_VENDOR: VariableVendor = VariableVendor(guid!("5a4d1b9e-6f2c-4a3d-9e7a-1c8f2b6d4e10"));
+
+// Packs a tile into one byte: bit 7 is `is_bomb`, bits 5-6 are `state`, bits 0-4
+// are `neighboring_bombs` (0-8 fits comfortably).
+fn pack_tile(tile: &Tile) -> u8 {
+    let state_bits: u8 = match tile.state {
+        TileState::Hidden => 0,
+        TileState::Revealed => 1,
+        TileState::Flagged => 2,
+    };
+    let bomb_bit: u8 = if tile.is_bomb { 1 } else { 0 };
+    (bomb_bit << 7) | (state_bits << 5) | (tile.neighboring_bombs & 0x1F)
+}
+
+fn unpack_tile(byte: u8) -> Tile {
+    Tile {
+        is_bomb: byte & 0x80 != 0,
+        state: match (byte >> 5) & 0x3 {
+            1 => TileState::Revealed,
+            2 => TileState::Flagged,
+            _ => TileState::Hidden,
+        },
+        neighboring_bombs: byte & 0x1F,
+    }
+}
+
+fn serialize(game: &Game) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(SAVE_LEN);
+    bytes.extend_from_slice(&MAGIC);
+    bytes.push(VERSION);
+    bytes.extend_from_slice(&game.rng.seed.to_le_bytes());
+    bytes.push(game.is_first_move as u8);
+    bytes.extend_from_slice(&game.start_seconds.to_le_bytes());
+    bytes.extend_from_slice(&(game.selection.x as u16).to_le_bytes());
+    bytes.extend_from_slice(&(game.selection.y as u16).to_le_bytes());
+    bytes.push(match game.status {
+        GameStatus::Playing => 0,
+        GameStatus::Win => 1,
+        GameStatus::Lose => 2,
+    });
+    for tile in &game.grid {
+        bytes.push(pack_tile(tile));
+    }
+    bytes
+}
+
+// Rebuilds a `Game` from a saved byte blob, rejecting anything that isn't our
+// exact magic/version/length so a stale or foreign variable is ignored rather
+// than half-decoded into garbage.
+fn deserialize(bytes: &[u8]) -> Option<Game> {
+    if bytes.len() != SAVE_LEN || bytes[0..4] != MAGIC || bytes[4] != VERSION {
+        return None;
+    }
+
+    let seed = u64::from_le_bytes(bytes[5..13].try_into().ok()?);
+    let is_first_move = bytes[13] != 0;
+    let start_seconds = u32::from_le_bytes(bytes[14..18].try_into().ok()?);
+    let sel_x = u16::from_le_bytes(bytes[18..20].try_into().ok()?) as usize;
+    let sel_y = u16::from_le_bytes(bytes[20..22].try_into().ok()?) as usize;
+    if sel_x >= GRID_WIDTH || sel_y >= GRID_HEIGHT {
+        return None;
+    }
+    let status = match bytes[22] {
+        1 => GameStatus::Win,
+        2 => GameStatus::Lose,
+        _ => GameStatus::Playing,
+    };
+
+    let tile_bytes = &bytes[23..23 + TILE_COUNT];
+    let grid = tile_bytes.iter().map(|&b| unpack_tile(b)).collect();
+
+    Some(Game {
+        grid,
+        selection: Vec2::new(sel_x, sel_y),
+        status,
+        is_first_move,
+        rng: Rng::new(seed),
+        start_seconds,
+        camera: Vec2::new(0, 0),
+        hint: None,
+        dirty_tiles: Vec::new(),
+        full_redraw: true,
+        fog_enabled: false,
+        fog_table: Vec::new(),
+    })
+}
+
+// Best-effort: a failed write (e.g. exhausted NVRAM) shouldn't crash the game,
+// it just means the next boot won't find anything to resume.
+pub(crate) fn save(game: &Game) {
+    let bytes = serialize(game);
+    let attributes = VariableAttributes::NON_VOLATILE
+        | VariableAttributes::BOOTSERVICE_ACCESS
+        | VariableAttributes::RUNTIME_ACCESS;
+    let _ = uefi::runtime::set_variable(SAVE_NAME, &SAVE_VENDOR, attributes, &bytes);
+}
+
+pub(crate) fn load() -> Option<Game> {
+    let size = uefi::runtime::get_variable_size(SAVE_NAME, &SAVE_VENDOR).ok()?;
+    let mut buf = vec![0u8; size];
+    let (bytes, _attributes) = uefi::runtime::get_variable(SAVE_NAME, &SAVE_VENDOR, &mut buf).ok()?;
+    deserialize(bytes)
+}
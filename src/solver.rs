@@ -0,0 +1,159 @@
+use alloc::vec::Vec;
+
+use crate::{neighbors_of, Tile, TileState, Vec2, GRID_HEIGHT, GRID_WIDTH};
+
+// A revealed numbered tile's still-unknown neighbors, and how many of them must
+// be mines. `cells` only ever holds `Hidden` tiles; flagged neighbors are assumed
+// correct and are folded into `mines` instead.
+struct Constraint {
+    cells: Vec<Vec2>,
+    mines: usize,
+}
+
+pub(crate) struct Deductions {
+    pub(crate) safe: Vec<Vec2>,
+    pub(crate) mines: Vec<Vec2>,
+}
+
+fn tile_at(grid: &[Tile], pos: Vec2) -> Tile {
+    grid[pos.y * GRID_WIDTH + pos.x]
+}
+
+fn build_constraints(grid: &[Tile]) -> Vec<Constraint> {
+    let mut constraints = Vec::new();
+    for y in 0..GRID_HEIGHT {
+        for x in 0..GRID_WIDTH {
+            let tile = tile_at(grid, Vec2::new(x, y));
+            if tile.state != TileState::Revealed || tile.is_bomb || tile.neighboring_bombs == 0 {
+                continue;
+            }
+            let neighbors = neighbors_of(x, y);
+            let cells: Vec<Vec2> = neighbors
+                .iter()
+                .copied()
+                .filter(|&p| tile_at(grid, p).state == TileState::Hidden)
+                .collect();
+            if cells.is_empty() {
+                continue;
+            }
+            let flagged = neighbors
+                .iter()
+                .filter(|&&p| tile_at(grid, p).state == TileState::Flagged)
+                .count();
+            let mines = (tile.neighboring_bombs as usize).saturating_sub(flagged);
+            constraints.push(Constraint { cells, mines });
+        }
+    }
+    constraints
+}
+
+fn mark(found: &mut Vec<Vec2>, other: &[Vec2], cell: Vec2) -> bool {
+    if found.contains(&cell) || other.contains(&cell) {
+        false
+    } else {
+        found.push(cell);
+        true
+    }
+}
+
+// Deduces which hidden tiles must be safe or must be mines from the revealed
+// numbers, via single-point deduction and the subset rule, run to a fixpoint:
+// - single-point: a constraint with 0 mines means all its cells are safe; a
+//   constraint whose mine count equals its cell count means all its cells are mines.
+// - subset: if constraint A's cells are a subset of constraint B's, the
+//   difference B\A must contain `B.mines - A.mines` mines.
+pub(crate) fn deduce(grid: &[Tile]) -> Deductions {
+    let constraints = build_constraints(grid);
+    let mut safe = Vec::new();
+    let mut mines = Vec::new();
+
+    loop {
+        let mut changed = false;
+
+        for c in &constraints {
+            if c.mines == 0 {
+                for &cell in &c.cells {
+                    changed |= mark(&mut safe, &mines, cell);
+                }
+            } else if c.mines == c.cells.len() {
+                for &cell in &c.cells {
+                    changed |= mark(&mut mines, &safe, cell);
+                }
+            }
+        }
+
+        for a in &constraints {
+            for b in &constraints {
+                if a.cells.len() >= b.cells.len() {
+                    continue;
+                }
+                if !a.cells.iter().all(|cell| b.cells.contains(cell)) {
+                    continue;
+                }
+                let diff: Vec<Vec2> = b
+                    .cells
+                    .iter()
+                    .copied()
+                    .filter(|cell| !a.cells.contains(cell))
+                    .collect();
+                if diff.is_empty() {
+                    continue;
+                }
+                let diff_mines = b.mines.saturating_sub(a.mines);
+                if diff_mines == 0 {
+                    for &cell in &diff {
+                        changed |= mark(&mut safe, &mines, cell);
+                    }
+                } else if diff_mines == diff.len() {
+                    for &cell in &diff {
+                        changed |= mark(&mut mines, &safe, cell);
+                    }
+                }
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    Deductions { safe, mines }
+}
+
+// Reveals `start` and floods outward through connected zero-count tiles, mirroring
+// the reveal semantics used during play, against a scratch grid.
+fn flood_reveal(grid: &mut [Tile], start: Vec2) {
+    let mut stack = Vec::new();
+    stack.push(start);
+    while let Some(pos) = stack.pop() {
+        let idx = pos.y * GRID_WIDTH + pos.x;
+        if grid[idx].state != TileState::Hidden {
+            continue;
+        }
+        grid[idx].state = TileState::Revealed;
+        if grid[idx].neighboring_bombs == 0 && !grid[idx].is_bomb {
+            for neighbor in neighbors_of(pos.x, pos.y) {
+                stack.push(neighbor);
+            }
+        }
+    }
+}
+
+// Simulates playing out `grid` from the first click at `start` using only
+// solver-certain moves (`deduce`'s single-point + subset rules). Returns whether
+// that alone clears the whole board. At Expert's mine density this succeeds only
+// a small fraction of the time — see the doc comment on `Game::plant_bombs` for
+// how the caller uses this as a best-effort bias rather than a real guarantee.
+pub(crate) fn is_solvable(mut grid: Vec<Tile>, start: Vec2) -> bool {
+    flood_reveal(&mut grid, start);
+    loop {
+        let deductions = deduce(&grid);
+        if deductions.safe.is_empty() {
+            break;
+        }
+        for cell in deductions.safe {
+            flood_reveal(&mut grid, cell);
+        }
+    }
+    grid.iter().all(|tile| tile.is_bomb || tile.state == TileState::Revealed)
+}